@@ -0,0 +1,213 @@
+//! Machine-readable benchmarking mode.
+//!
+//! For every registered `Sort`, [`run_benchmarks`] runs each pattern generator across a set of
+//! sizes, recording wall-clock time, exact comparison counts (tallied by the comparator closure;
+//! each element also carries a `Cell` counter, but elements are a plain struct and are not packed
+//! into a `u64` the way `observable_is_less_u64` does, so FFI-backed sorts whose `sort_by` only
+//! supports `i32`/`u64` can't run this), and the minimum number of swaps needed to realize the
+//! resulting permutation -- the
+//! cycle-decomposition lower bound, not a trace of the sort's actual internal moves, which FFI
+//! sorts don't expose. [`format_records`] then emits the results as CSV or JSON keyed by
+//! `(sort_name, pattern, len)`.
+//!
+//! `BENCH_PATTERNS` and `BENCH_SIZES` (comma separated) narrow which patterns/sizes run, and
+//! `BENCH_FORMAT` (`csv`, the default, or `json`) selects the output format, mirroring the
+//! `WRITE_LARGE_FAILURE` env var convention used by the correctness tests.
+
+use std::cell::Cell;
+use std::env;
+use std::time::Instant;
+
+use crate::patterns;
+use crate::Sort;
+
+const DEFAULT_BENCH_SIZES: &[usize] = &[10, 100, 1_000, 10_000, 100_000];
+
+type PatternFn = fn(usize) -> Vec<i32>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Csv,
+    Json,
+}
+
+impl OutputFormat {
+    fn from_env() -> Self {
+        match env::var("BENCH_FORMAT").as_deref() {
+            Ok("json") => OutputFormat::Json,
+            _ => OutputFormat::Csv,
+        }
+    }
+}
+
+/// One measured result for a single `(sort, pattern, len)` combination.
+#[derive(Debug, Clone)]
+pub struct BenchRecord {
+    pub sort_name: String,
+    pub pattern_name: &'static str,
+    pub len: usize,
+    pub elapsed_nanos: u128,
+    pub comparisons: u64,
+    /// Minimum swaps needed to realize the observed output permutation, per its cycle
+    /// decomposition. Not a trace of the sort's actual internal move count.
+    pub min_swaps: u64,
+}
+
+impl BenchRecord {
+    fn to_csv_row(&self) -> String {
+        format!(
+            "{},{},{},{},{},{}",
+            self.sort_name,
+            self.pattern_name,
+            self.len,
+            self.elapsed_nanos,
+            self.comparisons,
+            self.min_swaps
+        )
+    }
+
+    fn to_json(&self) -> String {
+        format!(
+            r#"{{"sort":"{}","pattern":"{}","len":{},"elapsed_nanos":{},"comparisons":{},"min_swaps":{}}}"#,
+            self.sort_name,
+            self.pattern_name,
+            self.len,
+            self.elapsed_nanos,
+            self.comparisons,
+            self.min_swaps
+        )
+    }
+}
+
+fn default_patterns() -> Vec<(&'static str, PatternFn)> {
+    vec![
+        ("random", patterns::random),
+        ("random_uniform_d4", |len| patterns::random_uniform(len, 0..4)),
+        ("ascending", patterns::ascending),
+        ("descending", patterns::descending),
+        ("all_equal", patterns::all_equal),
+        ("pipe_organ", patterns::pipe_organ),
+        ("saw_mixed", |len| {
+            patterns::saw_mixed(len, ((len as f64).log2().round()) as usize)
+        }),
+        ("random_zipf_1_0", |len| patterns::random_zipf(len, 1.0)),
+    ]
+}
+
+fn selected_patterns() -> Vec<(&'static str, PatternFn)> {
+    let all = default_patterns();
+    match env::var("BENCH_PATTERNS") {
+        Ok(filter) => {
+            let wanted: Vec<&str> = filter.split(',').map(str::trim).collect();
+            all.into_iter()
+                .filter(|(name, _)| wanted.contains(name))
+                .collect()
+        }
+        Err(_) => all,
+    }
+}
+
+fn selected_sizes() -> Vec<usize> {
+    match env::var("BENCH_SIZES") {
+        Ok(filter) => filter
+            .split(',')
+            .filter_map(|s| s.trim().parse::<usize>().ok())
+            .collect(),
+        Err(_) => DEFAULT_BENCH_SIZES.to_vec(),
+    }
+}
+
+/// Minimum number of swaps needed to turn `from_order` (where `from_order[i]` is the original
+/// index of the element now at position `i`) into the identity permutation: the classic
+/// `len - number_of_cycles` formula.
+fn min_swaps_for_permutation(from_order: &[u32]) -> u64 {
+    let len = from_order.len();
+    let mut visited = vec![false; len];
+    let mut swaps = 0u64;
+
+    for start in 0..len {
+        if visited[start] {
+            continue;
+        }
+
+        let mut cycle_len = 0u64;
+        let mut i = start;
+        while !visited[i] {
+            visited[i] = true;
+            i = from_order[i] as usize;
+            cycle_len += 1;
+        }
+        swaps += cycle_len - 1;
+    }
+
+    swaps
+}
+
+/// Runs every selected pattern/size combination through `S` once, recording timing, comparison,
+/// and swap-distance metrics.
+pub fn run_benchmarks<S: Sort>() -> Vec<BenchRecord> {
+    #[derive(Clone)]
+    struct Indexed {
+        val: i32,
+        orig_idx: u32,
+        comp_count: Cell<u64>,
+    }
+
+    let mut records = Vec::new();
+
+    for (pattern_name, pattern_fn) in selected_patterns() {
+        for &len in &selected_sizes() {
+            let mut indexed: Vec<Indexed> = pattern_fn(len)
+                .into_iter()
+                .enumerate()
+                .map(|(i, val)| Indexed {
+                    val,
+                    orig_idx: i as u32,
+                    comp_count: Cell::new(0),
+                })
+                .collect();
+
+            let mut comparisons = 0u64;
+            let start = Instant::now();
+            <S as Sort>::sort_by(&mut indexed, |a, b| {
+                a.comp_count.set(a.comp_count.get() + 1);
+                b.comp_count.set(b.comp_count.get() + 1);
+                comparisons += 1;
+                a.val.cmp(&b.val)
+            });
+            let elapsed_nanos = start.elapsed().as_nanos();
+
+            let permutation: Vec<u32> = indexed.iter().map(|x| x.orig_idx).collect();
+
+            records.push(BenchRecord {
+                sort_name: <S as Sort>::name().to_string(),
+                pattern_name,
+                len,
+                elapsed_nanos,
+                comparisons,
+                min_swaps: min_swaps_for_permutation(&permutation),
+            });
+        }
+    }
+
+    records
+}
+
+/// Serializes `records` according to `BENCH_FORMAT` (`csv`, the default, or `json`).
+pub fn format_records(records: &[BenchRecord]) -> String {
+    match OutputFormat::from_env() {
+        OutputFormat::Csv => {
+            let mut out =
+                String::from("sort_name,pattern,len,elapsed_nanos,comparisons,min_swaps\n");
+            for record in records {
+                out.push_str(&record.to_csv_row());
+                out.push('\n');
+            }
+            out
+        }
+        OutputFormat::Json => {
+            let rows: Vec<String> = records.iter().map(BenchRecord::to_json).collect();
+            format!("[{}]", rows.join(","))
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use std::cell::Cell;
 use std::cmp::Ordering;
+use std::collections::BTreeSet;
 use std::env;
 use std::fmt::Debug;
 use std::fs;
@@ -33,7 +34,14 @@ const TEST_SIZES: &[usize] = &[
 
 fn get_or_init_random_seed<S: Sort>() -> u64 {
     static SEED_WRITTEN: Mutex<bool> = Mutex::new(false);
-    let seed = patterns::random_init_seed();
+
+    // Mirrors the seeded `XorShiftRng::from_seed` approach used in upstream sort benches: forcing
+    // the seed here makes a flaky failure deterministically replayable by re-running with
+    // `OVERRIDE_SEED` set to the value printed below.
+    let seed = env::var("OVERRIDE_SEED")
+        .ok()
+        .and_then(|s| s.parse::<u64>().ok())
+        .unwrap_or_else(patterns::random_init_seed);
 
     let mut seed_writer = SEED_WRITTEN.lock().unwrap();
     if !*seed_writer {
@@ -49,6 +57,80 @@ fn get_or_init_random_seed<S: Sort>() -> u64 {
     seed
 }
 
+/// Re-runs the same correctness check `sort_comp` performs, on a standalone candidate slice, so it
+/// can serve as the `still_fails` predicate for [`ddmin`].
+fn sort_mismatches<T: Ord + Clone, S: Sort>(candidate: &[T]) -> bool {
+    if candidate.len() < 2 {
+        return false;
+    }
+
+    let mut expected = candidate.to_vec();
+    expected.sort();
+
+    let mut got = candidate.to_vec();
+    <S as Sort>::sort(&mut got);
+
+    expected != got
+}
+
+/// ddmin-style delta debugging: shrinks `input` to a smaller sequence that still satisfies
+/// `still_fails`, so a failure report carries a small, directly pasteable reproducer instead of a
+/// million-element dump.
+///
+/// Repeatedly partitions the input into `n` chunks (starting at `n = 2`); if removing any one
+/// chunk, or keeping only its complement, still fails, recurses on that smaller sequence and
+/// resets `n`. Otherwise doubles the granularity (`n = min(2 * n, len)`) until it exceeds the
+/// length, at which point the input can't be shrunk further.
+fn ddmin<T: Clone>(input: Vec<T>, still_fails: &mut impl FnMut(&[T]) -> bool) -> Vec<T> {
+    if input.len() < 2 || !still_fails(&input) {
+        return input;
+    }
+
+    let mut current = input;
+    let mut n = 2usize;
+
+    while current.len() >= 2 && n <= current.len() {
+        let chunk_size = (current.len() + n - 1) / n;
+        let mut shrunk = false;
+
+        let mut start = 0;
+        while start < current.len() {
+            let end = (start + chunk_size).min(current.len());
+
+            // Try the complement: every chunk except this one.
+            let mut complement = current.clone();
+            complement.drain(start..end);
+            if !complement.is_empty() && still_fails(&complement) {
+                current = complement;
+                n = 2;
+                shrunk = true;
+                break;
+            }
+
+            // Try just this one chunk on its own.
+            let chunk: Vec<T> = current[start..end].to_vec();
+            if chunk.len() < current.len() && still_fails(&chunk) {
+                current = chunk;
+                n = 2;
+                shrunk = true;
+                break;
+            }
+
+            start = end;
+        }
+
+        if !shrunk {
+            n = if n >= current.len() {
+                n + 1
+            } else {
+                (2 * n).min(current.len())
+            };
+        }
+    }
+
+    current
+}
+
 fn sort_comp<T: Ord + Clone + Debug, S: Sort>(v: &mut [T]) {
     let seed = get_or_init_random_seed::<S>();
 
@@ -89,6 +171,15 @@ fn sort_comp<T: Ord + Clone + Debug, S: Sort>(v: &mut [T]) {
                     "Failed comparison, re-run with WRITE_LARGE_FAILURE env var set, to get output."
                 );
                 }
+
+                let minimized = ddmin(original_clone.clone(), &mut |candidate: &[T]| {
+                    sort_mismatches::<T, S>(candidate)
+                });
+                eprintln!(
+                    "Seed: {seed}\nMinimized failing input ({} elements): {:?}",
+                    minimized.len(),
+                    minimized
+                );
             }
 
             panic!("Test assertion failed!")
@@ -103,24 +194,43 @@ fn test_impl<T: Ord + Clone + Debug, S: Sort>(pattern_fn: impl Fn(usize) -> Vec<
     }
 }
 
-fn test_impl_custom(mut test_fn: impl FnMut(usize, fn(usize) -> Vec<i32>)) {
-    let test_pattern_fns: Vec<fn(usize) -> Vec<i32>> = vec![
-        patterns::random,
-        |len| patterns::random_uniform(len, 0..=(((len as f64).log2().round()) as i32) as i32),
-        |len| patterns::random_uniform(len, 0..=1 as i32),
-        patterns::ascending,
-        patterns::descending,
-        |len| patterns::saw_mixed(len, ((len as f64).log2().round()) as usize),
-        |len| patterns::random_zipf(len, 1.0),
+fn test_impl_custom<S: Sort>(mut test_fn: impl FnMut(usize, fn(usize) -> Vec<i32>)) {
+    let test_pattern_fns: Vec<(&'static str, fn(usize) -> Vec<i32>)> = vec![
+        ("random", patterns::random),
+        ("random_uniform_log2", |len| {
+            patterns::random_uniform(len, 0..=(((len as f64).log2().round()) as i32) as i32)
+        }),
+        ("random_uniform_01", |len| {
+            patterns::random_uniform(len, 0..=1 as i32)
+        }),
+        ("ascending", patterns::ascending),
+        ("descending", patterns::descending),
+        ("saw_mixed", |len| {
+            patterns::saw_mixed(len, ((len as f64).log2().round()) as usize)
+        }),
+        ("random_zipf_1_0", |len| patterns::random_zipf(len, 1.0)),
     ];
 
-    for test_pattern_fn in test_pattern_fns {
+    let seed = get_or_init_random_seed::<S>();
+
+    for (pattern_name, test_pattern_fn) in test_pattern_fns {
         for test_len in &TEST_SIZES[..TEST_SIZES.len() - 2] {
             if *test_len < 2 {
                 continue;
             }
 
-            test_fn(*test_len, test_pattern_fn);
+            // Catch and re-raise so a failure carries everything needed to paste together a
+            // standalone reproducer, without changing pass/fail behavior for the caller.
+            let result =
+                panic::catch_unwind(AssertUnwindSafe(|| test_fn(*test_len, test_pattern_fn)));
+            if let Err(payload) = result {
+                eprintln!(
+                    "\nFailing reproducer info:\n  Sort: {}\n  Seed: {seed}\n  Pattern: {pattern_name}\n  test_len: {test_len}\n  Base i32 input: {:?}\n",
+                    <S as Sort>::name(),
+                    test_pattern_fn(*test_len),
+                );
+                panic::resume_unwind(payload);
+            }
         }
     }
 }
@@ -386,6 +496,25 @@ pub fn pipe_organ<S: Sort>() {
     test_impl::<i32, S>(patterns::pipe_organ);
 }
 
+pub fn mostly_ascending<S: Sort>() {
+    // Mostly-sorted input with a handful of random swaps is exactly the shape adaptive merge
+    // sorts (timsort/driftsort-style run detection) should shine on, and where run-detection bugs
+    // hide.
+    test_impl::<i32, S>(|test_len| {
+        patterns::mostly_ascending(test_len, (test_len / 20).max(1))
+    });
+}
+
+pub fn mostly_descending<S: Sort>() {
+    test_impl::<i32, S>(|test_len| {
+        patterns::mostly_descending(test_len, (test_len / 20).max(1))
+    });
+}
+
+pub fn random_str_var<S: Sort>() {
+    test_impl::<String, S>(patterns::random_str_var);
+}
+
 pub fn stability<S: Sort>() {
     let _seed = get_or_init_random_seed::<S>();
 
@@ -525,7 +654,7 @@ pub fn stability_with_patterns<S: Sort>() {
             .all(|w| i32_tup_from_u64(w[0]) <= i32_tup_from_u64(w[1])));
     };
 
-    test_impl_custom(test_fn);
+    test_impl_custom::<S>(test_fn);
 }
 
 pub fn random_ffi_str<S: Sort>() {
@@ -622,7 +751,7 @@ pub fn comp_panic<S: Sort>() {
         }
     };
 
-    test_impl_custom(test_fn);
+    test_impl_custom::<S>(test_fn);
 }
 
 pub fn observable_is_less_u64<S: Sort>() {
@@ -714,7 +843,7 @@ pub fn observable_is_less_u64<S: Sort>() {
         assert_eq!(total_inner, comp_count_global * 2);
     };
 
-    test_impl_custom(test_fn);
+    test_impl_custom::<S>(test_fn);
 }
 
 pub fn observable_is_less<S: Sort>() {
@@ -765,7 +894,7 @@ pub fn observable_is_less<S: Sort>() {
         assert_eq!(total_inner, comp_count_global * 2);
     };
 
-    test_impl_custom(test_fn);
+    test_impl_custom::<S>(test_fn);
 }
 
 pub fn observable_is_less_mut_ptr<S: Sort>() {
@@ -832,7 +961,7 @@ pub fn observable_is_less_mut_ptr<S: Sort>() {
         assert_eq!(total_inner, comp_count_global * 2);
     };
 
-    test_impl_custom(test_fn);
+    test_impl_custom::<S>(test_fn);
 }
 
 fn calc_comps_required<T: Clone, S: Sort>(
@@ -902,7 +1031,7 @@ pub fn panic_retain_original_set_impl<S: Sort, T: Ord + Clone>(
         // show up as double-free here.
     };
 
-    test_impl_custom(test_fn);
+    test_impl_custom::<S>(test_fn);
 }
 
 pub fn panic_retain_original_set_i32<S: Sort>() {
@@ -996,7 +1125,7 @@ fn panic_observable_is_less_impl<S: Sort, T: Ord + Clone>(
         assert_eq!(sum_before, sum_after);
     };
 
-    test_impl_custom(test_fn);
+    test_impl_custom::<S>(test_fn);
 }
 
 pub fn panic_observable_is_less_i32<S: Sort>() {
@@ -1052,7 +1181,7 @@ fn deterministic_impl<S: Sort, T: Ord + Clone + Debug>(
         assert_eq!(test_input, test_input_clone);
     };
 
-    test_impl_custom(test_fn);
+    test_impl_custom::<S>(test_fn);
 }
 
 pub fn deterministic_i32<S: Sort>() {
@@ -1095,7 +1224,7 @@ fn self_cmp_impl<S: Sort, T: Ord + Clone + Debug>(type_into_fn: impl Fn(i32) ->
         }
     };
 
-    test_impl_custom(test_fn);
+    test_impl_custom::<S>(test_fn);
 }
 
 pub fn self_cmp_i32<S: Sort>() {
@@ -1271,7 +1400,7 @@ fn violate_ord_retain_original_set_impl<S: Sort, T: Ord>(
             assert_eq!(sum_before, sum_after);
         };
 
-        test_impl_custom(test_fn);
+        test_impl_custom::<S>(test_fn);
 
         if cfg!(miri) {
             // This test is prohibitively expensive in miri, so only run one of the comparison
@@ -1297,6 +1426,351 @@ pub fn violate_ord_retain_original_set_cell_i32<S: Sort>() {
     violate_ord_retain_original_set_impl::<S, Cell<i32>>(|val| Cell::new(val), |val| val.get());
 }
 
+#[derive(Clone, Debug)]
+struct IdVal {
+    id: u64,
+    val: i32,
+}
+
+fn assert_ids_preserved(data: &[IdVal], expected_ids: &BTreeSet<u64>) {
+    let got_ids: BTreeSet<u64> = data.iter().map(|x| x.id).collect();
+    assert_eq!(data.len(), expected_ids.len());
+    assert_eq!(
+        &got_ids, expected_ids,
+        "sort lost or duplicated elements under an Ord-violating comparator"
+    );
+}
+
+pub fn ord_violation<S: Sort>() {
+    // `Ord`/comparator violations (non-transitive, non-antisymmetric, or nondeterministic) must
+    // never cause UB: no OOB access, no lost or duplicated elements. At worst, an arbitrary but
+    // valid permutation of the input. This is checked under Miri so any unsoundness surfaces as a
+    // hard error rather than a silent data corruption.
+    let seed = get_or_init_random_seed::<S>();
+
+    let test_fn = |test_len: usize, pattern_fn: fn(usize) -> Vec<i32>| {
+        let test_data: Vec<IdVal> = pattern_fn(test_len)
+            .into_iter()
+            .enumerate()
+            .map(|(id, val)| IdVal { id: id as u64, val })
+            .collect();
+
+        let expected_ids: BTreeSet<u64> = test_data.iter().map(|x| x.id).collect();
+
+        // (a) Reproducible pseudo-random ordering, seeded from the shared, deterministic test seed.
+        {
+            let mut data = test_data.clone();
+            let mut rng_state = seed ^ (test_len as u64).wrapping_mul(0x9e3779b97f4a7c15);
+            let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+                <S as Sort>::sort_by(&mut data, |_a, _b| {
+                    rng_state ^= rng_state << 13;
+                    rng_state ^= rng_state >> 7;
+                    rng_state ^= rng_state << 17;
+                    match rng_state % 3 {
+                        0 => Ordering::Less,
+                        1 => Ordering::Equal,
+                        _ => Ordering::Greater,
+                    }
+                });
+            }));
+            assert_ids_preserved(&data, &expected_ids);
+        }
+
+        // (b) Deliberately non-transitive: bucket by `val / 3`, breaking ties within a bucket by
+        // the parity of a shared mutable counter.
+        {
+            let mut data = test_data.clone();
+            let mut parity_counter = 0u32;
+            let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+                <S as Sort>::sort_by(&mut data, |a, b| {
+                    parity_counter = parity_counter.wrapping_add(1);
+                    let bucket_cmp = (a.val / 3).cmp(&(b.val / 3));
+                    if bucket_cmp != Ordering::Equal {
+                        bucket_cmp
+                    } else if parity_counter % 2 == 0 {
+                        Ordering::Less
+                    } else {
+                        Ordering::Greater
+                    }
+                });
+            }));
+            assert_ids_preserved(&data, &expected_ids);
+        }
+
+        // (c) The result depends on a mutating call count, so the apparent order "drifts" as the
+        // sort progresses.
+        {
+            let mut data = test_data.clone();
+            let mut calls = 0u64;
+            let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+                <S as Sort>::sort_by(&mut data, |a, b| {
+                    calls += 1;
+                    if calls % 7 == 0 {
+                        b.val.cmp(&a.val)
+                    } else {
+                        a.val.cmp(&b.val)
+                    }
+                });
+            }));
+            assert_ids_preserved(&data, &expected_ids);
+        }
+    };
+
+    test_impl_custom::<S>(test_fn);
+}
+
+/// Advertised worst-case comparison complexity of a `Sort` implementation, used by
+/// [`comparison_bound`] to flag algorithmic regressions. Sorts that don't report a class here are
+/// skipped by that check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComplexityClass {
+    /// No complexity claim is made; `comparison_bound` skips this sort.
+    Unknown,
+    /// Worst case is `O(n log n)` comparisons.
+    NLogN,
+}
+
+pub fn comparison_bound<S: Sort>() {
+    // Guards against an introselect/quicksort-style sort that fails to fall back to a heap/merge
+    // step and silently degrades to quadratic comparisons on a crafted input.
+    if <S as Sort>::worst_case() == ComplexityClass::Unknown {
+        return;
+    }
+
+    // Generous constant: this is a regression guard, not a tight bound.
+    const K: u32 = 25;
+
+    let bound = |test_len: usize| -> u32 {
+        let n = test_len.max(2) as f64;
+        (K as f64 * n * n.log2().ceil()) as u32
+    };
+
+    let test_fn = |test_len: usize, pattern_fn: fn(usize) -> Vec<i32>| {
+        let test_data = pattern_fn(test_len);
+        let comps = calc_comps_required::<i32, S>(&test_data, |a, b| a.cmp(b));
+        assert!(
+            comps <= bound(test_len),
+            "{} exceeded its advertised worst case: {} comparisons for n = {} (bound {})",
+            <S as Sort>::name(),
+            comps,
+            test_len,
+            bound(test_len)
+        );
+    };
+
+    test_impl_custom::<S>(test_fn);
+
+    // Targeted pivot-killer inputs, checked on top of the standard pattern set above (which already
+    // includes `saw_mixed`, but not the median-of-three killer or pipe organ).
+    for &test_len in &TEST_SIZES[..TEST_SIZES.len() - 2] {
+        if test_len < 4 {
+            continue;
+        }
+
+        let killer_inputs = [
+            patterns::median_of_three_killer(test_len),
+            patterns::pipe_organ(test_len),
+            patterns::saw_mixed(test_len, ((test_len as f64).log2().round()) as usize),
+        ];
+
+        for test_data in killer_inputs {
+            let comps = calc_comps_required::<i32, S>(&test_data, |a, b| a.cmp(b));
+            assert!(
+                comps <= bound(test_len),
+                "{} exceeded its advertised worst case on a pivot-killer input: {} comparisons for \
+                 n = {} (bound {})",
+                <S as Sort>::name(),
+                comps,
+                test_len,
+                bound(test_len)
+            );
+        }
+    }
+}
+
+/// Information-theoretic lower bound on the number of comparisons needed to sort `n` distinct
+/// elements by comparisons alone: `log2(n!)`, approximated via Stirling's formula as
+/// `n*log2(n) - n*log2(e) + O(log n)`.
+fn info_theoretic_lower_bound(n: usize) -> f64 {
+    if n < 2 {
+        return 0.0;
+    }
+
+    let n = n as f64;
+    n * n.log2() - n * std::f64::consts::LOG2_E + 0.5 * (2.0 * std::f64::consts::PI * n).log2()
+}
+
+fn assert_comparisons_near_lower_bound<T: Clone, S: Sort>(
+    test_len: usize,
+    tolerance: f64,
+    data: &[T],
+    cmp_fn: impl FnMut(&T, &T) -> Ordering,
+    type_name: &str,
+) {
+    let comps = calc_comps_required::<T, S>(data, cmp_fn);
+    let bound = tolerance * info_theoretic_lower_bound(test_len);
+    assert!(
+        comps as f64 <= bound,
+        "{} took {comps} comparisons sorting {test_len} random {type_name} values, exceeding \
+         {tolerance}x the information-theoretic lower bound ({bound:.1})",
+        <S as Sort>::name(),
+    );
+}
+
+pub fn comparison_lower_bound<S: Sort>() {
+    // Guards against an algorithmic regression (e.g. a merge policy or pivot change that doubles
+    // comparisons) that passes every correctness and panic-safety check above by comparing the
+    // measured comparison count on random input against the information-theoretic lower bound for
+    // comparison sorting. `FFIString` gets a looser tolerance: its comparator does more than a
+    // single machine comparison per call, which tends to show up as more, not fewer, calls to it.
+    for &test_len in TEST_SIZES {
+        if test_len < 2 {
+            continue;
+        }
+
+        assert_comparisons_near_lower_bound::<i32, S>(
+            test_len,
+            2.0,
+            &patterns::random(test_len),
+            |a, b| a.cmp(b),
+            "i32",
+        );
+
+        let u64_data: Vec<u64> = patterns::random(test_len)
+            .iter()
+            .map(|&v| v as i64 as u64)
+            .collect();
+        assert_comparisons_near_lower_bound::<u64, S>(test_len, 2.0, &u64_data, |a, b| a.cmp(b), "u64");
+
+        let str_data: Vec<FFIString> = patterns::random(test_len)
+            .iter()
+            .map(|val| FFIString::new(format!("{:010}", val.saturating_abs())))
+            .collect();
+        assert_comparisons_near_lower_bound::<FFIString, S>(
+            test_len,
+            3.0,
+            &str_data,
+            |a, b| a.cmp(b),
+            "FFIString",
+        );
+    }
+}
+
+pub fn random_runs<S: Sort>() {
+    // Correctness: `k` concatenated ascending/descending runs of randomized length is exactly the
+    // shape a run-merging (timsort/driftsort/glidesort-style) sort should detect and exploit.
+    for &k in &[2usize, 4, 8, 16] {
+        test_impl::<i32, S>(|test_len| patterns::random_runs(test_len, k));
+    }
+
+    // Adaptiveness: guards against a sort silently losing its run-detection fast path. Fully
+    // sorted input, and input built from a handful of runs, must stay close to linear in
+    // comparisons rather than falling back to the general O(n log n) case.
+    const C: u32 = 20;
+
+    for &test_len in &TEST_SIZES[..TEST_SIZES.len() - 2] {
+        if test_len < 4 {
+            continue;
+        }
+
+        for ascending in [true, false] {
+            let data = if ascending {
+                patterns::ascending(test_len)
+            } else {
+                patterns::descending(test_len)
+            };
+            let comps = calc_comps_required::<i32, S>(&data, |a, b| a.cmp(b));
+            let bound = C * test_len as u32;
+            assert!(
+                comps <= bound,
+                "{} took {} comparisons on a fully {} input of length {} (bound {})",
+                <S as Sort>::name(),
+                comps,
+                if ascending { "ascending" } else { "descending" },
+                test_len,
+                bound
+            );
+        }
+
+        for &k in &[2usize, 4, 8] {
+            let data = patterns::random_runs(test_len, k);
+            let comps = calc_comps_required::<i32, S>(&data, |a, b| a.cmp(b));
+            let n = test_len.max(2) as f64;
+            let bound = (C as f64 * n * (k.max(2) as f64).log2()) as u32;
+            assert!(
+                comps <= bound,
+                "{} took {} comparisons on a {}-run input of length {} (bound {})",
+                <S as Sort>::name(),
+                comps,
+                k,
+                test_len,
+                bound
+            );
+        }
+    }
+}
+
+pub fn mcilroy_killer<S: Sort>() {
+    // McIlroy's antiquicksort adversary: adaptively constructs a worst-case input for whatever
+    // `S` is under test by driving one probing sort run with a comparator that freezes values as
+    // it goes, then re-sorts the resulting killer permutation for real while counting
+    // comparisons.
+    let test_fn = |test_len: usize, _pattern_fn: fn(usize) -> Vec<i32>| {
+        if test_len < 4 {
+            return;
+        }
+
+        // Gas is reported as greater than every solid value, so an element still gas after the
+        // probing run must also be the largest value in the re-run.
+        const GAS: i32 = i32::MAX;
+
+        let mut val = vec![GAS; test_len];
+        let mut nsolid = 0i32;
+        let mut candidate = 0usize;
+
+        // The elements being sorted are their own original index, so the comparator can always
+        // recover "which element is this" regardless of how far the sort has moved it.
+        let mut indices: Vec<usize> = (0..test_len).collect();
+
+        <S as Sort>::sort_by(&mut indices, |&a, &b| {
+            if val[a] == GAS && val[b] == GAS {
+                let freeze = if a == candidate { a } else { b };
+                val[freeze] = nsolid;
+                nsolid += 1;
+            }
+
+            if val[a] == GAS {
+                candidate = a;
+                Ordering::Greater
+            } else if val[b] == GAS {
+                candidate = b;
+                Ordering::Less
+            } else {
+                val[a].cmp(&val[b])
+            }
+        });
+
+        // `val` is now the killer permutation. Re-run the real sort on a fresh array built from
+        // it, through an ordinary comparator, and assert the comparison count stays sub-quadratic.
+        let comps = calc_comps_required::<i32, S>(&val, |a, b| a.cmp(b));
+
+        const C: u32 = 20;
+        let n = test_len.max(2) as f64;
+        let bound = (C as f64 * n * n.log2()) as u32;
+
+        assert!(
+            comps <= bound,
+            "{} took {} comparisons on a McIlroy killer input of length {} (bound {})",
+            <S as Sort>::name(),
+            comps,
+            test_len,
+            bound
+        );
+    };
+
+    test_impl_custom::<S>(test_fn);
+}
+
 pub fn sort_vs_sort_by<S: Sort>() {
     let _seed = get_or_init_random_seed::<S>();
 
@@ -1396,15 +1870,19 @@ macro_rules! instantiate_sort_tests {
             [miri_yes, ascending],
             [miri_no, saw_ascending],
             [miri_yes, basic],
+            [miri_no, comparison_bound],
+            [miri_no, comparison_lower_bound],
             [miri_yes, comp_panic],
             [miri_yes, descending],
             [miri_no, saw_descending],
             [miri_yes, dyn_val],
             [miri_yes, fixed_seed],
             [miri_yes, int_edge],
+            [miri_no, mcilroy_killer],
             [miri_yes, observable_is_less],
             [miri_yes, observable_is_less_mut_ptr],
             [miri_yes, observable_is_less_u64],
+            [miri_yes, ord_violation],
             [miri_yes, panic_observable_is_less_i32],
             [miri_no, panic_observable_is_less_ffi_string],
             [miri_no, panic_observable_is_less_cell_i32],
@@ -1425,7 +1903,11 @@ macro_rules! instantiate_sort_tests {
             [miri_yes, random_narrow],
             [miri_yes, random_s50],
             [miri_yes, random_s95],
+            [miri_no, random_runs],
             [miri_no, random_str],
+            [miri_no, random_str_var],
+            [miri_no, mostly_ascending],
+            [miri_no, mostly_descending],
             [miri_yes, random_type_u128],
             [miri_yes, random_type_u64],
             [miri_yes, random_cell_i32],
@@ -18,6 +18,8 @@ extern "C" {
 use std::cmp::Ordering;
 
 use crate::ffi_util::rust_fn_cmp;
+use crate::float_order::{f32_from_key, f32_to_key, f64_from_key, f64_to_key};
+use crate::permutation;
 
 trait PdqSort: Sized {
     fn sort(data: &mut [Self]);
@@ -58,6 +60,55 @@ impl PdqSort for u64 {
     }
 }
 
+impl PdqSort for f64 {
+    fn sort(data: &mut [f64]) {
+        // There is no dedicated float FFI entry point; map to the order-preserving `u64` key and
+        // route through the existing `u64` sort, then map back.
+        let mut keys: Vec<u64> = data.iter().map(|x| f64_to_key(*x)).collect();
+        PdqSort::sort(keys.as_mut_slice());
+        for (slot, key) in data.iter_mut().zip(keys.iter()) {
+            *slot = f64_from_key(*key);
+        }
+    }
+
+    fn sort_by<F: FnMut(&f64, &f64) -> Ordering>(data: &mut [f64], mut compare: F) {
+        // No key mapping needed here: the caller's comparator is forwarded as-is, reinterpreting
+        // each `f64`'s bit pattern as the `u64` the FFI trampoline expects.
+        let mut keys: Vec<u64> = data.iter().map(|x| x.to_bits()).collect();
+        PdqSort::sort_by(keys.as_mut_slice(), |a, b| {
+            compare(&f64::from_bits(*a), &f64::from_bits(*b))
+        });
+        for (slot, key) in data.iter_mut().zip(keys.iter()) {
+            *slot = f64::from_bits(*key);
+        }
+    }
+}
+
+impl PdqSort for f32 {
+    fn sort(data: &mut [f32]) {
+        // No native 32-bit unstable sort FFI entry point exists, so the order-preserving key is
+        // widened into the high bits of the `u64` lane the FFI already provides.
+        let mut keys: Vec<u64> = data.iter().map(|x| (f32_to_key(*x) as u64) << 32).collect();
+        PdqSort::sort(keys.as_mut_slice());
+        for (slot, key) in data.iter_mut().zip(keys.iter()) {
+            *slot = f32_from_key((*key >> 32) as u32);
+        }
+    }
+
+    fn sort_by<F: FnMut(&f32, &f32) -> Ordering>(data: &mut [f32], mut compare: F) {
+        let mut keys: Vec<u64> = data.iter().map(|x| (x.to_bits() as u64) << 32).collect();
+        PdqSort::sort_by(keys.as_mut_slice(), |a, b| {
+            compare(
+                &f32::from_bits((*a >> 32) as u32),
+                &f32::from_bits((*b >> 32) as u32),
+            )
+        });
+        for (slot, key) in data.iter_mut().zip(keys.iter()) {
+            *slot = f32::from_bits((*key >> 32) as u32);
+        }
+    }
+}
+
 pub fn sort<T: Ord>(data: &mut [T]) {
     PdqSort::sort(data);
 }
@@ -65,3 +116,29 @@ pub fn sort<T: Ord>(data: &mut [T]) {
 pub fn sort_by<T, F: FnMut(&T, &T) -> Ordering>(data: &mut [T], compare: F) {
     PdqSort::sort_by(data, compare);
 }
+
+/// Sorts a permutation of `0..data.len()` by `compare(&data[i], &data[j])` instead of reordering
+/// `data` itself, so the same permutation can be applied to several parallel arrays via
+/// [`apply_permutation`].
+///
+/// Routes through the existing `i32` FFI trampoline: ordering is fully determined by `compare`, so
+/// the indices' bit pattern never needs to be interpreted as a signed integer.
+pub fn sort_to_indices<T, F: FnMut(&T, &T) -> Ordering>(data: &[T], compare: F) -> Vec<u32> {
+    permutation::sort_to_indices(data, compare, |indices, cmp| {
+        sort_by(indices, |a, b| cmp(a, b))
+    })
+}
+
+/// Reorders `data` in place so that `data[i]` becomes the element that used to be at
+/// `data[indices[i]]`, as produced by [`sort_to_indices`].
+///
+/// # Safety
+///
+/// `indices` must be a permutation of `0..data.len()`; see [`permutation::apply_permutation`].
+pub unsafe fn apply_permutation<T>(data: &mut [T], indices: &[u32]) {
+    // SAFETY: delegated to the caller's own safety requirements, which mirror
+    // `permutation::apply_permutation`'s.
+    unsafe {
+        permutation::apply_permutation(data, indices);
+    }
+}
@@ -1,6 +1,8 @@
 use std::cmp::Ordering;
 
 use crate::ffi_util::{rust_fn_cmp, CompResult};
+use crate::float_order::{f32_from_key, f32_to_key, f64_from_key, f64_to_key};
+use crate::permutation;
 
 extern "C" {
     fn sort_stable_i32(data: *mut i32, len: usize);
@@ -58,6 +60,55 @@ impl CppSort for u64 {
     }
 }
 
+impl CppSort for f64 {
+    fn sort(data: &mut [f64]) {
+        // There is no dedicated float FFI entry point; map to the order-preserving `u64` key and
+        // route through the existing `u64` sort, then map back.
+        let mut keys: Vec<u64> = data.iter().map(|x| f64_to_key(*x)).collect();
+        CppSort::sort(keys.as_mut_slice());
+        for (slot, key) in data.iter_mut().zip(keys.iter()) {
+            *slot = f64_from_key(*key);
+        }
+    }
+
+    fn sort_by<F: FnMut(&f64, &f64) -> Ordering>(data: &mut [f64], mut compare: F) {
+        // No key mapping needed here: the caller's comparator is forwarded as-is, reinterpreting
+        // each `f64`'s bit pattern as the `u64` the FFI trampoline expects.
+        let mut keys: Vec<u64> = data.iter().map(|x| x.to_bits()).collect();
+        CppSort::sort_by(keys.as_mut_slice(), |a, b| {
+            compare(&f64::from_bits(*a), &f64::from_bits(*b))
+        });
+        for (slot, key) in data.iter_mut().zip(keys.iter()) {
+            *slot = f64::from_bits(*key);
+        }
+    }
+}
+
+impl CppSort for f32 {
+    fn sort(data: &mut [f32]) {
+        // No native 32-bit stable sort FFI entry point exists, so the order-preserving key is
+        // widened into the high bits of the `u64` lane the FFI already provides.
+        let mut keys: Vec<u64> = data.iter().map(|x| (f32_to_key(*x) as u64) << 32).collect();
+        CppSort::sort(keys.as_mut_slice());
+        for (slot, key) in data.iter_mut().zip(keys.iter()) {
+            *slot = f32_from_key((*key >> 32) as u32);
+        }
+    }
+
+    fn sort_by<F: FnMut(&f32, &f32) -> Ordering>(data: &mut [f32], mut compare: F) {
+        let mut keys: Vec<u64> = data.iter().map(|x| (x.to_bits() as u64) << 32).collect();
+        CppSort::sort_by(keys.as_mut_slice(), |a, b| {
+            compare(
+                &f32::from_bits((*a >> 32) as u32),
+                &f32::from_bits((*b >> 32) as u32),
+            )
+        });
+        for (slot, key) in data.iter_mut().zip(keys.iter()) {
+            *slot = f32::from_bits((*key >> 32) as u32);
+        }
+    }
+}
+
 pub fn sort<T: Ord>(data: &mut [T]) {
     CppSort::sort(data);
 }
@@ -65,3 +116,87 @@ pub fn sort<T: Ord>(data: &mut [T]) {
 pub fn sort_by<T, F: FnMut(&T, &T) -> Ordering>(data: &mut [T], compare: F) {
     CppSort::sort_by(data, compare);
 }
+
+/// Sorts a permutation of `0..data.len()` by `compare(&data[i], &data[j])` instead of reordering
+/// `data` itself, so the same permutation can be applied to several parallel arrays via
+/// [`apply_permutation`].
+///
+/// Routes through the existing `i32` FFI trampoline: ordering is fully determined by `compare`, so
+/// the indices' bit pattern never needs to be interpreted as a signed integer.
+pub fn sort_to_indices<T, F: FnMut(&T, &T) -> Ordering>(data: &[T], compare: F) -> Vec<u32> {
+    permutation::sort_to_indices(data, compare, |indices, cmp| {
+        sort_by(indices, |a, b| cmp(a, b))
+    })
+}
+
+/// Reorders `data` in place so that `data[i]` becomes the element that used to be at
+/// `data[indices[i]]`, as produced by [`sort_to_indices`].
+///
+/// # Safety
+///
+/// `indices` must be a permutation of `0..data.len()`; see [`permutation::apply_permutation`].
+pub unsafe fn apply_permutation<T>(data: &mut [T], indices: &[u32]) {
+    // SAFETY: delegated to the caller's own safety requirements, which mirror
+    // `permutation::apply_permutation`'s.
+    unsafe {
+        permutation::apply_permutation(data, indices);
+    }
+}
+
+/// Controls null placement and direction for [`sort_with_options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SortOptions {
+    /// Reverse the order of the non-null values.
+    pub descending: bool,
+    /// Place all `None` entries before the non-null run instead of after it.
+    pub nulls_first: bool,
+}
+
+/// Sorts `data` according to `opts`: `None` entries are grouped at the front or back depending on
+/// `opts.nulls_first`, and the non-null run is sorted by `T`'s order, reversed if
+/// `opts.descending`. This mirrors the null-placement and direction options found in columnar /
+/// database sort implementations.
+pub fn sort_with_options<T: Ord>(data: &mut [Option<T>], opts: SortOptions) {
+    let none_count = data.iter().filter(|x| x.is_none()).count();
+
+    // Stable partition: gather indices with nulls grouped to the chosen end, preserving relative
+    // order within each group, then apply that permutation in one shot.
+    let indices: Vec<u32> = if opts.nulls_first {
+        (0..data.len() as u32)
+            .filter(|&i| data[i as usize].is_none())
+            .chain((0..data.len() as u32).filter(|&i| data[i as usize].is_some()))
+            .collect()
+    } else {
+        (0..data.len() as u32)
+            .filter(|&i| data[i as usize].is_some())
+            .chain((0..data.len() as u32).filter(|&i| data[i as usize].is_none()))
+            .collect()
+    };
+    // SAFETY: `indices` chains the `is_none()` indices with the `is_some()` indices (or vice
+    // versa), each built by filtering the full `0..data.len()` range, so together they contain
+    // every index in that range exactly once.
+    unsafe {
+        apply_permutation(data, &indices);
+    }
+
+    let some_start = if opts.nulls_first { none_count } else { 0 };
+    let some_end = some_start + (data.len() - none_count);
+
+    let mut values: Vec<T> = data[some_start..some_end]
+        .iter_mut()
+        .map(|slot| slot.take().unwrap())
+        .collect();
+
+    sort_by(&mut values, |a, b| {
+        let ord = a.cmp(b);
+        if opts.descending {
+            ord.reverse()
+        } else {
+            ord
+        }
+    });
+
+    for (slot, value) in data[some_start..some_end].iter_mut().zip(values) {
+        *slot = Some(value);
+    }
+}
@@ -0,0 +1,46 @@
+//! Order-preserving bit mappings from IEEE-754 floats to unsigned integers.
+//!
+//! Shared by the `CppSort`/`PdqSort` FFI adapters so `f32`/`f64` can be routed through the
+//! comparison-free `u64` FFI entry points (`f32` keys are widened into the high half of a `u64`)
+//! instead of requiring a dedicated float comparator on the C++ side.
+
+/// Maps `x`'s bits to a `u32` that sorts identically to `x` under IEEE-754 total order: negative
+/// numbers before negative zero before positive zero before positive numbers, by magnitude within
+/// each sign. NaN payloads sort to the extremes deterministically, by their raw bit pattern.
+#[inline]
+pub(crate) fn f32_to_key(x: f32) -> u32 {
+    let bits = x.to_bits();
+    let mask = (((bits as i32) >> 31) as u32) | (1 << 31);
+    bits ^ mask
+}
+
+/// Inverse of [`f32_to_key`].
+#[inline]
+pub(crate) fn f32_from_key(key: u32) -> f32 {
+    let mask = if (key >> 31) & 1 == 1 {
+        1u32 << 31
+    } else {
+        u32::MAX
+    };
+    f32::from_bits(key ^ mask)
+}
+
+/// Maps `x`'s bits to a `u64` that sorts identically to `x` under IEEE-754 total order. See
+/// [`f32_to_key`] for the placement this establishes.
+#[inline]
+pub(crate) fn f64_to_key(x: f64) -> u64 {
+    let bits = x.to_bits();
+    let mask = (((bits as i64) >> 63) as u64) | (1 << 63);
+    bits ^ mask
+}
+
+/// Inverse of [`f64_to_key`].
+#[inline]
+pub(crate) fn f64_from_key(key: u64) -> f64 {
+    let mask = if (key >> 63) & 1 == 1 {
+        1u64 << 63
+    } else {
+        u64::MAX
+    };
+    f64::from_bits(key ^ mask)
+}
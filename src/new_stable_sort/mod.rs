@@ -1,5 +1,9 @@
 #![allow(unused)]
 
+// `stable_sort_in`'s `A: Allocator` bound depends on the `allocator_api` nightly feature. Inner
+// `#![feature(...)]` attributes are only accepted at the crate root, so this module can't declare
+// it itself; the crate root is responsible for `#![feature(allocator_api)]`.
+use std::alloc::Allocator;
 use std::cmp::Ordering;
 use std::mem;
 use std::ptr;
@@ -30,12 +34,71 @@ where
         return;
     }
 
-    merge_sort(v, &mut is_less);
+    let mut buf: Vec<T> = Vec::new();
+    merge_sort(v, &mut is_less, &mut buf);
+}
+
+/// Scratch memory reused across repeated [`stable_sort_with_scratch`] calls, so sorting many
+/// slices in a loop doesn't hit the global allocator on every call.
+///
+/// Grows to accommodate the largest slice sorted through it so far and never shrinks; pass the
+/// same `SortScratch` to every call in a loop to amortize its allocation.
+pub struct SortScratch<T> {
+    buf: Vec<T>,
+}
+
+impl<T> SortScratch<T> {
+    /// Creates an empty scratch buffer. No allocation happens until the first sort that needs one.
+    pub fn new() -> Self {
+        Self { buf: Vec::new() }
+    }
+}
+
+impl<T> Default for SortScratch<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[inline]
+pub fn stable_sort_with_scratch<T, F>(v: &mut [T], scratch: &mut SortScratch<T>, mut is_less: F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    if mem::size_of::<T>() == 0 {
+        // Sorting has no meaningful behavior on zero-sized types. Do nothing.
+        return;
+    }
+
+    merge_sort(v, &mut is_less, &mut scratch.buf);
+}
+
+/// Like [`stable_sort`], but allocates its scratch memory via `alloc` instead of the global
+/// allocator.
+#[inline]
+pub fn stable_sort_in<T, F, A: Allocator>(v: &mut [T], alloc: A, mut is_less: F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    if mem::size_of::<T>() == 0 {
+        // Sorting has no meaningful behavior on zero-sized types. Do nothing.
+        return;
+    }
+
+    let mut buf: Vec<T, A> = Vec::new_in(alloc);
+    merge_sort(v, &mut is_less, &mut buf);
 }
 
 // Slices of up to this length get sorted using insertion sort.
 const MAX_INSERTION: usize = 20;
 
+// Copy slices of up to this length get sorted using the allocation-free mini-merge sort, instead
+// of paying for a heap-allocated merge buffer.
+const MAX_MINI_MERGE: usize = 32;
+
+// Block size the mini-merge sort's first pass sorts with the branchless `sort8` network.
+const MINI_MERGE_BLOCK: usize = 8;
+
 // Sort a small number of elements as fast as possible, without allocations.
 #[inline]
 fn sort_small<T, F>(v: &mut [T], is_less: &mut F)
@@ -48,7 +111,7 @@ where
         return;
     }
 
-    if T::is_copy() {
+    if T::is_copy() || T::branchless_ok() {
         unsafe {
             if len == 2 {
                 sort2(v, is_less);
@@ -72,7 +135,132 @@ where
     }
 }
 
-fn merge_sort<T, F>(v: &mut [T], is_less: &mut F)
+// `sort_small`'s branchless networks above `len < 4` aren't documented to preserve the order of
+// equal elements; [`sort4_stable`]/[`sort8_stable`] are the dedicated stable replacements for that
+// range, used by [`sort_small_stable`] below. There's no stable branchless network above this
+// length, so `sort_small_stable` falls back to the plain (but genuinely stable) insertion sort for
+// `SMALL_SORT_STABLE_THRESHOLD < len <= MAX_INSERTION`, which is the whole range `merge_sort`
+// routes through `sort_small_stable`.
+const SMALL_SORT_STABLE_THRESHOLD: usize = 8;
+
+/// Like [`sort_small`], but when `use_stable` is set, guarantees a stable result for every
+/// `v.len()` up to `MAX_INSERTION` (the whole range `merge_sort` calls this for): lengths in
+/// `4..=SMALL_SORT_STABLE_THRESHOLD` route through the dedicated stable networks
+/// ([`sort4_stable`]/[`sort8_stable`]), and lengths above that fall back to the plain stable
+/// insertion sort (`sort_small`'s branchless networks above `len < 4` aren't documented to preserve
+/// the order of equal elements, so they can't be used here). Below `len < 4`, `sort_small`'s own
+/// paths (`sort2`/`sort3`/insertion sort) are already stable, so `use_stable` makes no difference
+/// there.
+#[inline]
+fn sort_small_stable<T, F>(v: &mut [T], use_stable: bool, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let len = v.len();
+
+    if !use_stable || len < 4 {
+        sort_small(v, is_less);
+        return;
+    }
+
+    if len > SMALL_SORT_STABLE_THRESHOLD || !(T::is_copy() || T::branchless_ok()) {
+        for i in (0..len - 1).rev() {
+            insert_head(&mut v[i..], is_less);
+        }
+        return;
+    }
+
+    let mut src_copy = mem::MaybeUninit::<[T; SMALL_SORT_STABLE_THRESHOLD]>::uninit();
+    let mut scratch = mem::MaybeUninit::<[T; SMALL_SORT_STABLE_THRESHOLD]>::uninit();
+    let src_ptr = src_copy.as_mut_ptr() as *mut T;
+    let scratch_ptr = scratch.as_mut_ptr() as *mut T;
+    let dst_ptr = v.as_mut_ptr();
+
+    // SAFETY: `len` was just checked to be in `4..=SMALL_SORT_STABLE_THRESHOLD`, and
+    // `T::is_copy() || T::branchless_ok()` was just checked to hold.
+    unsafe {
+        ptr::copy_nonoverlapping(dst_ptr, src_ptr, len);
+
+        if len < 8 {
+            sort4_stable(src_ptr, scratch_ptr, dst_ptr, is_less);
+            insertion_sort_remaining(v, 4, is_less);
+        } else {
+            sort8_stable(src_ptr, scratch_ptr, dst_ptr, is_less);
+        }
+    }
+}
+
+/// Sorts `v` (`MAX_INSERTION < v.len() <= MAX_MINI_MERGE`) without any heap allocation: sorts
+/// fixed `MINI_MERGE_BLOCK`-element blocks with the existing branchless `sort8` network (the
+/// trailing partial block, if any, via insertion sort), then repeatedly merges adjacent sorted
+/// blocks pairwise with [`merge`] until a single run remains.
+///
+/// `merge`'s own scratch requirement is at most half of `v.len()`, so a single
+/// `MAX_MINI_MERGE / 2`-element stack array covers every merge step; no heap buffer is ever
+/// involved.
+///
+/// # Safety
+///
+/// `v.len()` must be in `(MAX_INSERTION, MAX_MINI_MERGE]`, and `T::is_copy()` must hold: this
+/// performs shallow bit-copies of `T` via [`sort8`] and [`merge`], which is only sound for `Copy`
+/// types.
+unsafe fn mini_merge_sort<T, F>(v: &mut [T], is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let len = v.len();
+    debug_assert!(len > MAX_INSERTION && len <= MAX_MINI_MERGE);
+
+    // At most `ceil(MAX_MINI_MERGE / MINI_MERGE_BLOCK)` blocks fit in `v`.
+    const MAX_BLOCKS: usize = (MAX_MINI_MERGE + MINI_MERGE_BLOCK - 1) / MINI_MERGE_BLOCK;
+
+    let mut swap = mem::MaybeUninit::<[T; MAX_MINI_MERGE / 2]>::uninit();
+    let buf_ptr = swap.as_mut_ptr() as *mut T;
+
+    // Sort fixed-size blocks, recording the bounds of each resulting run.
+    let mut run_starts = [0usize; MAX_BLOCKS];
+    let mut run_ends = [0usize; MAX_BLOCKS];
+    let mut num_runs = 0;
+    let mut start = 0;
+    while start < len {
+        let end = (start + MINI_MERGE_BLOCK).min(len);
+        if end - start == MINI_MERGE_BLOCK {
+            sort8(&mut v[start..end], is_less);
+        } else {
+            for i in (start..end - 1).rev() {
+                insert_head(&mut v[i..end], is_less);
+            }
+        }
+        run_starts[num_runs] = start;
+        run_ends[num_runs] = end;
+        num_runs += 1;
+        start = end;
+    }
+
+    // Merge adjacent runs pairwise, halving the run count each pass, until one remains.
+    while num_runs > 1 {
+        let mut next_runs = 0;
+        let mut i = 0;
+        while i < num_runs {
+            if i + 1 < num_runs {
+                let run_start = run_starts[i];
+                let mid = run_ends[i] - run_start;
+                let run_end = run_ends[i + 1];
+                merge(&mut v[run_start..run_end], mid, buf_ptr, is_less);
+                run_starts[next_runs] = run_start;
+                run_ends[next_runs] = run_end;
+            } else {
+                run_starts[next_runs] = run_starts[i];
+                run_ends[next_runs] = run_ends[i];
+            }
+            next_runs += 1;
+            i += 2;
+        }
+        num_runs = next_runs;
+    }
+}
+
+fn merge_sort<T, F, A: Allocator>(v: &mut [T], is_less: &mut F, buf: &mut Vec<T, A>)
 where
     F: FnMut(&T, &T) -> bool,
 {
@@ -83,17 +271,30 @@ where
 
     let len = v.len();
 
-    // Short arrays get sorted in-place via insertion sort to avoid allocations.
+    // Short arrays get sorted in-place via insertion sort to avoid allocations. Routed through
+    // `sort_small_stable` so lengths in `4..=SMALL_SORT_STABLE_THRESHOLD` use the dedicated stable
+    // networks instead of `sort_small`'s plain (not stability-documented) branchless ones.
     if len <= MAX_INSERTION {
-        sort_small(v, is_less);
+        sort_small_stable(v, true, is_less);
+        return;
+    }
+
+    // Slightly longer Copy arrays still avoid the heap entirely, via a stack-scratch mini-merge
+    // sort built from the same branchless primitives.
+    if len <= MAX_MINI_MERGE && T::is_copy() {
+        // SAFETY: `T::is_copy()` was just checked, and `len` is in `(MAX_INSERTION, MAX_MINI_MERGE]`.
+        unsafe {
+            mini_merge_sort(v, is_less);
+        }
         return;
     }
 
-    // Allocate a buffer to use as scratch memory. We keep the length 0 so we can keep in it
-    // shallow copies of the contents of `v` without risking the dtors running on copies if
-    // `is_less` panics. When merging two sorted runs, this buffer holds a copy of the shorter run,
-    // which will always have length at most `len / 2`.
-    let mut buf = Vec::with_capacity(len / 2);
+    // Grow the scratch buffer to hold the shorter of the two runs being merged (at most `len / 2`)
+    // only if it isn't already big enough, and leave it allocated afterwards for the caller to
+    // reuse on a subsequent call. We keep the length 0 so we can keep in it shallow copies of the
+    // contents of `v` without risking the dtors running on copies if `is_less` panics.
+    buf.clear();
+    buf.reserve(len / 2);
 
     // In order to identify natural runs in `v`, we traverse it backwards. That might seem like a
     // strange decision, but consider the fact that merges more often go in the opposite direction
@@ -220,7 +421,8 @@ where
     let start_found = start;
     let start_end_diff = end - start;
 
-    if T::is_copy() && start_end_diff < MAX_PRE_SORT16 && start_found >= 16 {
+    if (T::is_copy() || T::branchless_ok()) && start_end_diff < MAX_PRE_SORT16 && start_found >= 16
+    {
         unsafe {
             start = start_found.unchecked_sub(16);
             sort16(&mut v[start..start_found], is_less);
@@ -396,11 +598,21 @@ where
 /// Merges non-decreasing runs `v[..mid]` and `v[mid..]` using `buf` as temporary storage, and
 /// stores the result into `v[..]`.
 ///
+/// Copies the shorter of the two runs into `buf` and then drives a scalar comparison loop that
+/// adapts to long one-sided runs (e.g. concatenated presorted input) by galloping: see the
+/// `gallop_left`/`gallop_right` helpers below. This handles every split, including equal-length
+/// runs -- the branchless `merge_up`/`merge_down` tandem `parity_merge` uses can't be reused here,
+/// since those rely on both runs living in one untouched, read-only source buffer, whereas here the
+/// longer run is read directly out of `v`, which is also this function's destination.
+///
+/// `pub(crate)` so [`crate::parallel_merge_sort`] can reuse this as the sequential leaf merge once
+/// its recursive split has narrowed a merge down below its own parallelism threshold.
+///
 /// # Safety
 ///
 /// The two slices must be non-empty and `mid` must be in bounds. Buffer `buf` must be long enough
 /// to hold a copy of the shorter slice. Also, `T` must not be a zero-sized type.
-unsafe fn merge<T, F>(v: &mut [T], mid: usize, buf: *mut T, is_less: &mut F)
+pub(crate) unsafe fn merge<T, F>(v: &mut [T], mid: usize, buf: *mut T, is_less: &mut F)
 where
     F: FnMut(&T, &T) -> bool,
 {
@@ -443,16 +655,65 @@ where
         let mut right = v_mid;
         let out = &mut hole.dest;
 
+        // One comparison per element, except that once the same side has won `gallop` times
+        // in a row (seeded at `MIN_GALLOP`, TimSort's starting point) the merge switches to
+        // galloping: an exponential probe followed by a binary search brackets how many more
+        // elements of the winning side belong before the other side's current head, and that
+        // whole block is bulk-copied in one move, skipping the per-element
+        // comparisons for it. `gallop` is lowered when a gallop paid off and raised when it
+        // didn't, so inputs without long one-sided runs settle back into the cheap linear path.
+        let mut gallop = MIN_GALLOP;
+        let mut left_streak = 0usize;
+        let mut right_streak = 0usize;
+
         while *left < hole.end && right < v_end {
-            // Consume the lesser side.
-            // If equal, prefer the left run to maintain stability.
             unsafe {
-                let to_copy = if is_less(&*right, &**left) {
-                    get_and_increment(&mut right)
+                if left_streak >= gallop || right_streak >= gallop {
+                    let galloped = if left_streak >= gallop {
+                        let remaining = hole.end.sub_ptr(*left);
+                        let count = gallop_left(&*right, *left, remaining, is_less);
+                        if count > 0 {
+                            ptr::copy_nonoverlapping(*left, *out, count);
+                            *left = left.add(count);
+                            *out = out.add(count);
+                        }
+                        count > 0
+                    } else {
+                        let remaining = v_end.sub_ptr(right);
+                        let count = gallop_right(&**left, right, remaining, is_less);
+                        if count > 0 {
+                            // `out` trails `right` by the unconsumed left count, which can be
+                            // smaller than `count`, so the ranges may overlap.
+                            ptr::copy(right, *out, count);
+                            right = right.add(count);
+                            *out = out.add(count);
+                        }
+                        count > 0
+                    };
+
+                    left_streak = 0;
+                    right_streak = 0;
+                    gallop = if galloped {
+                        gallop.saturating_sub(1).max(1)
+                    } else {
+                        gallop + 1
+                    };
+                    continue;
+                }
+
+                // Consume the lesser side.
+                // If equal, prefer the left run to maintain stability.
+                if is_less(&*right, &**left) {
+                    let to_copy = get_and_increment(&mut right);
+                    ptr::copy_nonoverlapping(to_copy, get_and_increment(out), 1);
+                    right_streak += 1;
+                    left_streak = 0;
                 } else {
-                    get_and_increment(left)
-                };
-                ptr::copy_nonoverlapping(to_copy, get_and_increment(out), 1);
+                    let to_copy = get_and_increment(left);
+                    ptr::copy_nonoverlapping(to_copy, get_and_increment(out), 1);
+                    left_streak += 1;
+                    right_streak = 0;
+                }
             }
         }
     } else {
@@ -471,22 +732,72 @@ where
         let right = &mut hole.end;
         let mut out = v_end;
 
+        // See the mirror-image comment in the left-run-shorter branch above for how galloping
+        // works; here it runs back-to-front, bulk-copying from whichever end is on a winning
+        // streak.
+        let mut gallop = MIN_GALLOP;
+        let mut left_streak = 0usize;
+        let mut right_streak = 0usize;
+
         while arr_ptr < *left && buf < *right {
-            // Consume the greater side.
-            // If equal, prefer the right run to maintain stability.
             unsafe {
-                let to_copy = if is_less(&*right.offset(-1), &*left.offset(-1)) {
-                    decrement_and_get(left)
+                if left_streak >= gallop || right_streak >= gallop {
+                    let galloped = if left_streak >= gallop {
+                        let remaining = left.sub_ptr(arr_ptr);
+                        let count = gallop_left_rev(&*right.sub(1), *left, remaining, is_less);
+                        if count > 0 {
+                            *left = left.sub(count);
+                            out = out.sub(count);
+                            // `out` leads `left` by the unconsumed right count, which can be
+                            // smaller than `count`, so the ranges may overlap.
+                            ptr::copy(*left, out, count);
+                        }
+                        count > 0
+                    } else {
+                        let remaining = right.sub_ptr(buf);
+                        let count = gallop_right_rev(&*left.sub(1), *right, remaining, is_less);
+                        if count > 0 {
+                            *right = right.sub(count);
+                            out = out.sub(count);
+                            ptr::copy_nonoverlapping(*right, out, count);
+                        }
+                        count > 0
+                    };
+
+                    left_streak = 0;
+                    right_streak = 0;
+                    gallop = if galloped {
+                        gallop.saturating_sub(1).max(1)
+                    } else {
+                        gallop + 1
+                    };
+                    continue;
+                }
+
+                // Consume the greater side.
+                // If equal, prefer the right run to maintain stability.
+                if is_less(&*right.offset(-1), &*left.offset(-1)) {
+                    let to_copy = decrement_and_get(left);
+                    ptr::copy_nonoverlapping(to_copy, decrement_and_get(&mut out), 1);
+                    left_streak += 1;
+                    right_streak = 0;
                 } else {
-                    decrement_and_get(right)
-                };
-                ptr::copy_nonoverlapping(to_copy, decrement_and_get(&mut out), 1);
+                    let to_copy = decrement_and_get(right);
+                    ptr::copy_nonoverlapping(to_copy, decrement_and_get(&mut out), 1);
+                    right_streak += 1;
+                    left_streak = 0;
+                }
             }
         }
     }
     // Finally, `hole` gets dropped. If the shorter run was not fully consumed, whatever remains of
     // it will now be copied into the hole in `v`.
 
+    // Starting threshold for galloping mode, the point at which the merge gives up on one
+    // comparison per element and starts searching for a bulk-copyable block instead. Mirrors
+    // TimSort's `MIN_GALLOP`.
+    const MIN_GALLOP: usize = 7;
+
     unsafe fn get_and_increment<T>(ptr: &mut *mut T) -> *mut T {
         let old = *ptr;
         *ptr = unsafe { ptr.offset(1) };
@@ -498,6 +809,176 @@ where
         *ptr
     }
 
+    /// Counts the leading elements of the left run (`left_len` elements starting at `left_ptr`)
+    /// that still lose to `right`, i.e. the largest `k` such that `!is_less(right, left_ptr[i])`
+    /// holds for every `i < k`. Used while galloping a left-run winning streak: that whole block
+    /// can be bulk-copied ahead of `right` without any further comparisons.
+    unsafe fn gallop_left<T, F>(
+        right: &T,
+        left_ptr: *const T,
+        left_len: usize,
+        is_less: &mut F,
+    ) -> usize
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        unsafe {
+            if left_len == 0 || is_less(right, &*left_ptr) {
+                return 0;
+            }
+
+            let mut last_ofs = 0usize;
+            let mut ofs = 1usize;
+            while ofs < left_len && !is_less(right, &*left_ptr.add(ofs)) {
+                last_ofs = ofs;
+                ofs = ofs * 2 + 1;
+            }
+            if ofs > left_len {
+                ofs = left_len;
+            }
+
+            let mut lo = last_ofs + 1;
+            let mut hi = ofs;
+            while lo < hi {
+                let mid = lo + (hi - lo) / 2;
+                if !is_less(right, &*left_ptr.add(mid)) {
+                    lo = mid + 1;
+                } else {
+                    hi = mid;
+                }
+            }
+            lo
+        }
+    }
+
+    /// Mirror image of [`gallop_left`] for a right-run winning streak: counts the leading elements
+    /// of the right run (`right_len` elements starting at `right_ptr`) that still win against
+    /// `left`, i.e. the largest `k` such that `is_less(right_ptr[i], left)` holds for every
+    /// `i < k`.
+    unsafe fn gallop_right<T, F>(
+        left: &T,
+        right_ptr: *const T,
+        right_len: usize,
+        is_less: &mut F,
+    ) -> usize
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        unsafe {
+            if right_len == 0 || !is_less(&*right_ptr, left) {
+                return 0;
+            }
+
+            let mut last_ofs = 0usize;
+            let mut ofs = 1usize;
+            while ofs < right_len && is_less(&*right_ptr.add(ofs), left) {
+                last_ofs = ofs;
+                ofs = ofs * 2 + 1;
+            }
+            if ofs > right_len {
+                ofs = right_len;
+            }
+
+            let mut lo = last_ofs + 1;
+            let mut hi = ofs;
+            while lo < hi {
+                let mid = lo + (hi - lo) / 2;
+                if is_less(&*right_ptr.add(mid), left) {
+                    lo = mid + 1;
+                } else {
+                    hi = mid;
+                }
+            }
+            lo
+        }
+    }
+
+    /// Backward counterpart of [`gallop_left`]: counts the trailing elements of the left run
+    /// (`left_len` elements ending, exclusive, at `left_end`) that still win against `right_last`
+    /// in the back-to-front merge, i.e. the largest `k` such that
+    /// `is_less(right_last, left_end[-1 - i])` holds for every `i < k` (equal elements stay ahead
+    /// of `right_last`), probing from `left_end` backwards.
+    unsafe fn gallop_left_rev<T, F>(
+        right_last: &T,
+        left_end: *const T,
+        left_len: usize,
+        is_less: &mut F,
+    ) -> usize
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        unsafe {
+            if left_len == 0 || !is_less(right_last, &*left_end.sub(1)) {
+                return 0;
+            }
+
+            let mut last_ofs = 0usize;
+            let mut ofs = 1usize;
+            while ofs < left_len && is_less(right_last, &*left_end.sub(ofs + 1)) {
+                last_ofs = ofs;
+                ofs = ofs * 2 + 1;
+            }
+            if ofs > left_len {
+                ofs = left_len;
+            }
+
+            let mut lo = last_ofs + 1;
+            let mut hi = ofs;
+            while lo < hi {
+                let mid = lo + (hi - lo) / 2;
+                if is_less(right_last, &*left_end.sub(mid + 1)) {
+                    lo = mid + 1;
+                } else {
+                    hi = mid;
+                }
+            }
+            lo
+        }
+    }
+
+    /// Backward counterpart of [`gallop_right`]: counts the trailing elements of the right run
+    /// (`right_len` elements ending, exclusive, at `right_end`) that still win against
+    /// `left_last` in the back-to-front merge, i.e. the largest `k` such that
+    /// `!is_less(right_end[-1 - i], left_last)` holds for every `i < k` (equal elements stay
+    /// behind `left_last`), probing from `right_end` backwards.
+    unsafe fn gallop_right_rev<T, F>(
+        left_last: &T,
+        right_end: *const T,
+        right_len: usize,
+        is_less: &mut F,
+    ) -> usize
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        unsafe {
+            if right_len == 0 || is_less(&*right_end.sub(1), left_last) {
+                return 0;
+            }
+
+            let mut last_ofs = 0usize;
+            let mut ofs = 1usize;
+            while ofs < right_len && !is_less(&*right_end.sub(ofs + 1), left_last) {
+                last_ofs = ofs;
+                ofs = ofs * 2 + 1;
+            }
+            if ofs > right_len {
+                ofs = right_len;
+            }
+
+            let mut lo = last_ofs + 1;
+            let mut hi = ofs;
+            while lo < hi {
+                let mid = lo + (hi - lo) / 2;
+                if !is_less(&*right_end.sub(mid + 1), left_last) {
+                    lo = mid + 1;
+                } else {
+                    hi = mid;
+                }
+            }
+            lo
+        }
+    }
+
     // When dropped, copies the range `start..end` into `dest..`.
     struct MergeHole<T> {
         start: *mut T,
@@ -532,6 +1013,36 @@ impl<T: Copy> IsCopy<T> for T {
     }
 }
 
+/// Opt-in marker for non-`Copy` types that are nonetheless safe to move via shallow bit-copies.
+/// Implementing this lets a type (a newtype around an integer, a `#[repr(transparent)]` id, a
+/// small POD struct) use the same branchless sorting-network fast path as primitive `Copy` types
+/// instead of falling back to the element-by-element insertion sort.
+///
+/// # Safety
+///
+/// `Self` must have no `Drop` impl, no fields with interior mutability whose duplication could
+/// violate an invariant, and no uniqueness requirement (nothing like a guard type or a handle that
+/// must not be duplicated): temporarily duplicating `Self`'s bit pattern via
+/// `ptr::read`/`copy_nonoverlapping` and discarding one of the copies without running a destructor
+/// must be sound.
+pub unsafe trait BranchlessSortable {}
+
+trait MaybeBranchlessSortable<T> {
+    fn branchless_ok() -> bool;
+}
+
+impl<T> MaybeBranchlessSortable<T> for T {
+    default fn branchless_ok() -> bool {
+        false
+    }
+}
+
+impl<T: BranchlessSortable> MaybeBranchlessSortable<T> for T {
+    fn branchless_ok() -> bool {
+        true
+    }
+}
+
 // --- Branchless sorting (less branches not zero) ---
 
 /// Swap value with next value in array pointed to by arr_ptr if should_swap is true.
@@ -789,6 +1300,11 @@ where
     //
     // The caller must guarantee that the values of `dest_ptr[0..len]` have trivial
     // destructors that are sound to be called on a shallow copy of T.
+    //
+    // This function has no panic safety of its own: if `dest_ptr` points into memory the caller
+    // still owns (as opposed to scratch space), the caller is responsible for guarding it (e.g.
+    // with a [`ParityMergeHole`]) so an `is_less` panic can't leave it holding duplicated or
+    // missing elements.
 
     // Eg. src == [2, 3, 4, 7, 0, 1, 6, 8]
     //
@@ -830,12 +1346,30 @@ unsafe fn parity_merge<T, F>(src_ptr: *const T, dest_ptr: *mut T, len: usize, is
 where
     F: FnMut(&T, &T) -> bool,
 {
+    // This assumes `len` is even and splits the source at `len / 2`, so every caller must hand it
+    // two equal-length sorted halves (see `sort16`/`sort8_stable`'s calls below). A generalized
+    // version taking independent `len_l`/`len_r` was attempted and reverted: the up-pass and
+    // down-pass provisional writes meeting mid-buffer (the same speculative over-write technique
+    // `merge_up`/`merge_down` use) only cancel out cleanly when both passes run the same number of
+    // iterations *and* that number lines up with where the source was actually split, which holds
+    // by construction for the symmetric `len / 2` case but has to be re-derived from scratch for
+    // arbitrary `len_l`/`len_r` -- exactly the kind of hand-derived branchless-merge reasoning that
+    // produced a silent data-corruption bug elsewhere in this module (see `merge`'s history). Left
+    // unimplemented rather than risk landing another unsound derivation; `sort4_stable`/
+    // `sort8_stable` cover the odd-length small-sort cases this would have served via a plain
+    // stable insertion sort instead (see `sort_small_stable`).
+    //
     // SAFETY: the caller must guarantee that `src_ptr` and `dest_ptr` are valid for writes and
     // properly aligned. And they point to a contiguous owned region of memory each at least len
     // elements long. Also `src_ptr` and `dest_ptr` must not alias.
     //
     // The caller must guarantee that the values of `dest_ptr[0..len]` have trivial
     // destructors that are sound to be called on a shallow copy of T.
+    //
+    // This function has no panic safety of its own: if `dest_ptr` points into memory the caller
+    // still owns (as opposed to scratch space), the caller is responsible for guarding it (e.g.
+    // with a [`ParityMergeHole`]) so an `is_less` panic can't leave it holding duplicated or
+    // missing elements.
     let mut block = len / 2;
 
     let mut ptr_left = src_ptr;
@@ -859,9 +1393,50 @@ where
     finish_down(t_ptr_left, t_ptr_right, t_ptr_data, is_less);
 }
 
-// This implementation is only valid for Copy types. For these reasons:
-// 1. Panic safety
-// 2. Uniqueness preservation for types with interior mutability.
+/// When dropped, restores `dest[0..len]` to hold exactly the `len` values originally at
+/// `src[0..len]` (in their pre-merge order), so a panic partway through a bidirectional branchless
+/// merge that writes into memory the caller still owns can't leave it holding duplicated or
+/// missing elements. Call [`ParityMergeHole::finish`] once the merge completes successfully, which
+/// disarms the guard without running it.
+///
+/// `src` must be a read-only snapshot that the merge never writes through: since it's never
+/// mutated, restoring `dest` from it doesn't need to track how far the merge had progressed. The
+/// cheapest correct recovery is to simply re-copy the whole snapshot over `dest`, discarding
+/// whatever partial (and possibly duplicated) progress the merge had made. The result is unsorted,
+/// but every original element is present in `dest` exactly once, which is all panic safety
+/// requires; `parity_merge`/`parity_merge8` themselves make no such promise, so this only needs to
+/// wrap the calls whose `dest` is the caller's real backing storage rather than scratch space.
+struct ParityMergeHole<T> {
+    src: *const T,
+    dest: *mut T,
+    len: usize,
+}
+
+impl<T> ParityMergeHole<T> {
+    /// The merge completed successfully: `dest` already holds every element in sorted order, so
+    /// disarm the guard instead of letting it clobber that result.
+    #[inline]
+    fn finish(self) {
+        mem::forget(self);
+    }
+}
+
+impl<T> Drop for ParityMergeHole<T> {
+    fn drop(&mut self) {
+        // SAFETY: `src` and `dest` are valid for `len` elements each, per the invariant the
+        // constructing code upholds.
+        unsafe {
+            ptr::copy_nonoverlapping(self.src, self.dest, self.len);
+        }
+    }
+}
+
+// Previously this was only valid for `Copy` types, for two reasons:
+// 1. Panic safety: the `ParityMergeHole` guard below now restores `v` if `is_less` panics
+//    mid-merge, so this reason no longer applies.
+// 2. Uniqueness preservation for types with interior mutability: still applies. Callers must gate
+//    on `T::is_copy() || T::branchless_ok()`, and `BranchlessSortable`'s safety contract is what
+//    rules this concern out for non-Copy types.
 unsafe fn sort8<T, F>(v: &mut [T], is_less: &mut F)
 where
     F: FnMut(&T, &T) -> bool,
@@ -883,12 +1458,18 @@ where
     // We know the two parts v[0..4] and v[4..8] are already sorted.
     // So just create the scratch space.
     ptr::copy_nonoverlapping(arr_ptr, swap_ptr, 8);
+
+    // `parity_merge8` writes into `arr_ptr`, i.e. `v`'s own backing storage, so guard it.
+    let hole = ParityMergeHole {
+        src: swap_ptr,
+        dest: arr_ptr,
+        len: 8,
+    };
     parity_merge8(swap_ptr, arr_ptr, is_less);
+    hole.finish();
 }
 
-// This implementation is only valid for Copy types. For these reasons:
-// 1. Panic safety
-// 2. Uniqueness preservation for types with interior mutability.
+// See `sort8`'s comment on why this is no longer restricted to `Copy` types.
 unsafe fn sort16<T, F>(v: &mut [T], is_less: &mut F)
 where
     F: FnMut(&T, &T) -> bool,
@@ -915,13 +1496,85 @@ where
     let mut swap = mem::MaybeUninit::<[T; 16]>::uninit();
     let swap_ptr = swap.as_mut_ptr() as *mut T;
 
-    // Merge the already sorted v[0..4] with v[4..8] into swap.
+    // Merge the already sorted v[0..4] with v[4..8] into swap. `v` itself is untouched by this (it
+    // is only read from), so no guard is needed for it.
     parity_merge8(arr_ptr, swap_ptr, is_less);
-    // Merge the already sorted v[8..12] with v[12..16] into swap.
+    // Merge the already sorted v[8..12] with v[12..16] into swap. Same as above: `v` is untouched.
     parity_merge8(arr_ptr.add(8), swap_ptr.add(8), is_less);
 
     // v is still the same as before parity_merge8
     // swap now contains a shallow copy of v and is sorted in v[0..8] and v[8..16]
-    // Merge the two partitions.
+    // Merge the two partitions. This is the one call that writes into `v`'s own backing storage,
+    // so it's the one that needs guarding.
+    let hole = ParityMergeHole {
+        src: swap_ptr,
+        dest: arr_ptr,
+        len: 16,
+    };
     parity_merge(swap_ptr, arr_ptr, 16, is_less);
+    hole.finish();
+}
+
+/// Stably sorts the 4 elements at `src[0..4]` into `dst[0..4]`, using `scratch[0..4]` as
+/// intermediate storage, without mutating `src`.
+///
+/// Sorts each adjacent pair (`src[0..2]` and `src[2..4]`) into `scratch` with a single conditional
+/// swap on strict "right < left" (so equal elements keep their source order), then merges the two
+/// sorted pairs from `scratch` into `dst` with [`parity_merge`]. `parity_merge`'s `merge_up` and
+/// `merge_down` already break ties toward the left and right partition respectively (see
+/// `merge_up`'s and `finish_up`'s use of `!is_less(right, left)`, and `merge_down`'s and
+/// `finish_down`'s mirrored preference for the right partition), which is exactly what keeps this
+/// merge — and therefore this whole function — stable; that tie-breaking is a correctness
+/// invariant this function relies on, not an implementation detail.
+///
+/// # Safety
+///
+/// `src` must be valid for 4 reads, and `scratch`/`dst` valid for 4 writes each, with `src`,
+/// `scratch`, and `dst` pairwise non-overlapping. `T` must not be a zero-sized type, and the values
+/// read from `src` must have trivial destructors that are sound to call on a shallow copy of `T`.
+unsafe fn sort4_stable<T, F>(src: *const T, scratch: *mut T, dst: *mut T, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    ptr::copy_nonoverlapping(src, scratch, 2);
+    swap_next_if_less(scratch, is_less);
+    ptr::copy_nonoverlapping(src.add(2), scratch.add(2), 2);
+    swap_next_if_less(scratch.add(2), is_less);
+
+    let hole = ParityMergeHole {
+        src: scratch,
+        dest: dst,
+        len: 4,
+    };
+    parity_merge(scratch, dst, 4, is_less);
+    hole.finish();
+}
+
+/// Stably sorts the 8 elements at `src[0..8]` into `dst[0..8]`, using `scratch[0..8]` as
+/// intermediate storage, without mutating `src`.
+///
+/// Stably sorts each half of 4 elements into `scratch` via [`sort4_stable`] (reusing `dst`'s own
+/// memory as that call's scratch, since `dst` isn't read until the final merge below), then merges
+/// the two sorted halves from `scratch` into `dst` with [`parity_merge`]. As with `sort4_stable`,
+/// stability here rests on `merge_up`/`merge_down`'s documented tie-breaking behavior.
+///
+/// # Safety
+///
+/// Same as `sort4_stable`, but for 8 elements: `src` valid for 8 reads, `scratch`/`dst` valid for 8
+/// writes each, pairwise non-overlapping, `T` not zero-sized, values read from `src` trivially
+/// droppable as a shallow copy.
+unsafe fn sort8_stable<T, F>(src: *const T, scratch: *mut T, dst: *mut T, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    sort4_stable(src, dst, scratch, is_less);
+    sort4_stable(src.add(4), dst.add(4), scratch.add(4), is_less);
+
+    let hole = ParityMergeHole {
+        src: scratch,
+        dest: dst,
+        len: 8,
+    };
+    parity_merge(scratch, dst, 8, is_less);
+    hole.finish();
 }
\ No newline at end of file
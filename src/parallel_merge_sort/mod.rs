@@ -0,0 +1,236 @@
+//! Parallel stable sort built on Rayon's join/work-stealing model.
+//!
+//! [`sort`]/[`sort_by`] recursively split the slice in half, sort each half on a separate task via
+//! `rayon::join`, then merge the two sorted halves with [`parallel_merge`]: the longer run is split
+//! at its own midpoint, the matching split point in the shorter run is located with a binary
+//! search, and the resulting two independent `(left, right)` pairs are merged in parallel the same
+//! way. Below [`PARALLEL_THRESHOLD`] elements (or once Rayon reports no spare parallelism), both the
+//! sort and the merge fall back entirely to the sequential [`new_stable_sort`] primitives --
+//! including its branchless `sort16`/`parity_merge8` small-sort networks -- so the parallel driver
+//! only pays task-spawning and binary-search overhead where it can make it back.
+
+use std::mem;
+
+use rayon::current_num_threads;
+
+use crate::new_stable_sort::{self, SortScratch};
+
+/// Below this many elements, both splitting a sort and splitting a merge fall back entirely to the
+/// sequential path: the overhead of spawning a task and binary-searching a merge split isn't worth
+/// it.
+const PARALLEL_THRESHOLD: usize = 4096;
+
+/// Wraps a raw pointer so it can be captured by a `rayon::join` closure and sent to another thread.
+/// Sound only because every caller in this module hands out `SendPtr`s whose underlying ranges are
+/// provably disjoint from the range any other in-flight `SendPtr` points into (each covers a
+/// different, non-overlapping half of the shared merge scratch buffer), so no two threads ever
+/// write through overlapping memory.
+#[derive(Clone, Copy)]
+struct SendPtr<T>(*mut T);
+
+unsafe impl<T> Send for SendPtr<T> {}
+
+impl<T> SendPtr<T> {
+    #[inline]
+    fn get(self) -> *mut T {
+        self.0
+    }
+}
+
+#[inline]
+fn have_spare_parallelism() -> bool {
+    current_num_threads() > 1
+}
+
+/// Sorts `v` with the default `Ord` comparison, parallelizing across Rayon's thread pool for
+/// inputs large enough to benefit.
+pub fn sort<T>(v: &mut [T])
+where
+    T: Ord + Send,
+{
+    sort_by(v, |a, b| a.lt(b));
+}
+
+/// Sorts `v` with `is_less`, parallelizing across Rayon's thread pool for inputs large enough to
+/// benefit, and falling back to the sequential [`new_stable_sort::stable_sort_with_scratch`] below
+/// [`PARALLEL_THRESHOLD`] elements or once the pool reports no spare parallelism.
+pub fn sort_by<T, F>(v: &mut [T], is_less: F)
+where
+    T: Send,
+    F: Fn(&T, &T) -> bool + Sync,
+{
+    if mem::size_of::<T>() == 0 {
+        return;
+    }
+
+    let len = v.len();
+    if len <= PARALLEL_THRESHOLD || !have_spare_parallelism() {
+        sequential_sort(v, &is_less);
+        return;
+    }
+
+    // Scratch shared by every parallel merge below: each merge only ever touches the region of
+    // this buffer aligned with its own (disjoint) slice of `v`, so concurrent merges never
+    // contend over the same memory despite sharing one allocation.
+    let mut merge_buf: Vec<T> = Vec::with_capacity(len);
+    let merge_buf_ptr = SendPtr(merge_buf.as_mut_ptr());
+
+    parallel_sort(v, &is_less, merge_buf_ptr);
+}
+
+#[inline]
+fn sequential_sort<T, F>(v: &mut [T], is_less: &F)
+where
+    F: Fn(&T, &T) -> bool,
+{
+    let mut scratch = SortScratch::new();
+    new_stable_sort::stable_sort_with_scratch(v, &mut scratch, |a, b| is_less(a, b));
+}
+
+fn parallel_sort<T, F>(v: &mut [T], is_less: &F, merge_buf: SendPtr<T>)
+where
+    T: Send,
+    F: Fn(&T, &T) -> bool + Sync,
+{
+    let len = v.len();
+
+    if len <= PARALLEL_THRESHOLD || !have_spare_parallelism() {
+        sequential_sort(v, is_less);
+        return;
+    }
+
+    let mid = len / 2;
+    let (left, right) = v.split_at_mut(mid);
+    let right_buf = SendPtr(merge_buf.get().wrapping_add(mid));
+
+    // `left`/`right` are disjoint `&mut` sub-slices (courtesy of `split_at_mut`), so both halves
+    // can be sorted concurrently; `merge_buf`/`right_buf` likewise address disjoint halves of the
+    // shared scratch allocation.
+    rayon::join(
+        || parallel_sort(left, is_less, merge_buf),
+        || parallel_sort(right, is_less, right_buf),
+    );
+
+    // SAFETY: `v[..mid]` and `v[mid..]` are both fully sorted runs by this point, and `merge_buf`
+    // has room for at least `len` elements starting at `merge_buf.get()`.
+    unsafe {
+        parallel_merge(v, mid, merge_buf.get(), is_less);
+    }
+}
+
+/// Finds the number of leading elements of `v` that are `is_less` than `pivot`, i.e. the insertion
+/// point that keeps `pivot` *before* every element equal to it.
+///
+/// Use this when `pivot` comes from the run that sorts *before* `v` (i.e. `v` is the run being
+/// searched on the right): keeping ties in `v` after `pivot` is what keeps `pivot`'s original run
+/// ahead of `v`'s in the merge output.
+fn partition_point_lower<T, F>(v: &[T], pivot: &T, is_less: &F) -> usize
+where
+    F: Fn(&T, &T) -> bool,
+{
+    let mut lo = 0;
+    let mut hi = v.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if is_less(&v[mid], pivot) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// Finds the number of leading elements of `v` that are `is_less` than or equal to `pivot`, i.e.
+/// the insertion point that keeps `pivot` *after* every element equal to it.
+///
+/// Use this when `pivot` comes from the run that sorts *after* `v` (i.e. `v` is the run being
+/// searched on the left): keeping ties in `v` before `pivot` is what keeps `v`'s original run ahead
+/// of `pivot`'s in the merge output.
+fn partition_point_upper<T, F>(v: &[T], pivot: &T, is_less: &F) -> usize
+where
+    F: Fn(&T, &T) -> bool,
+{
+    let mut lo = 0;
+    let mut hi = v.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if is_less(pivot, &v[mid]) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    lo
+}
+
+/// Merges the two sorted runs `v[..mid]` and `v[mid..]`, parallelizing across Rayon's thread pool
+/// for inputs large enough to benefit.
+///
+/// Splits the longer run at its own midpoint, binary-searches the matching split point into the
+/// shorter run with [`partition_point_lower`]/[`partition_point_upper`], and rotates the two middle
+/// chunks so the resulting two independent `(left, right)` pairs each become contiguous sub-ranges
+/// of `v` before recursing on them in parallel. The recursion bottoms out, below
+/// [`PARALLEL_THRESHOLD`], in a single sequential [`new_stable_sort::merge`] call.
+///
+/// # Safety
+///
+/// Same as [`new_stable_sort::merge`]: both runs must be sorted and `mid` must be in bounds. `buf`
+/// must have room for at least `v.len()` elements (not just the shorter run, since nested splits
+/// may need scratch anywhere within that range). `T` must not be a zero-sized type.
+unsafe fn parallel_merge<T, F>(v: &mut [T], mid: usize, buf: *mut T, is_less: &F)
+where
+    T: Send,
+    F: Fn(&T, &T) -> bool + Sync,
+{
+    let len = v.len();
+
+    if mid == 0 || mid == len {
+        return;
+    }
+
+    if len <= PARALLEL_THRESHOLD || !have_spare_parallelism() {
+        let mut is_less_mut = |a: &T, b: &T| is_less(a, b);
+        // SAFETY: delegated to the caller's own safety requirements, which mirror `merge`'s.
+        unsafe {
+            new_stable_sort::merge(v, mid, buf, &mut is_less_mut);
+        }
+        return;
+    }
+
+    let (left_len, right_len) = (mid, len - mid);
+
+    // Split the longer run at its own midpoint, and binary-search where that element lands in the
+    // shorter run, so both halves of the recursion stay roughly balanced regardless of which run
+    // is longer.
+    let (split_in_left, split_in_right) = if left_len >= right_len {
+        // `pivot` comes from the left run, which sorts before the right run being searched: a
+        // lower-bound split keeps ties in the right run after `pivot`, i.e. after the left run.
+        let split_in_left = left_len / 2;
+        let split_in_right = mid + partition_point_lower(&v[mid..], &v[split_in_left], is_less);
+        (split_in_left, split_in_right)
+    } else {
+        // `pivot` comes from the right run, which sorts after the left run being searched: an
+        // upper-bound split keeps ties in the left run before `pivot`, i.e. before the right run.
+        let split_in_right = mid + right_len / 2;
+        let split_in_left = partition_point_upper(&v[..mid], &v[split_in_right], is_less);
+        (split_in_left, split_in_right)
+    };
+
+    // Right now `v` reads `[left[..split_in_left], left[split_in_left..mid],
+    // right[mid..split_in_right], right[split_in_right..]]`. Rotating the middle two chunks swaps
+    // them, so the front pair (`left[..split_in_left]` merged with `right[mid..split_in_right]`)
+    // and the back pair (`left[split_in_left..mid]` merged with `right[split_in_right..]`) each
+    // become contiguous sub-ranges that can be merged independently.
+    v[split_in_left..split_in_right].rotate_left(mid - split_in_left);
+
+    let front_len = split_in_left + (split_in_right - mid);
+    let (front, back) = v.split_at_mut(front_len);
+    let back_buf = SendPtr(buf.wrapping_add(front_len));
+    let buf = SendPtr(buf);
+
+    rayon::join(
+        || unsafe { parallel_merge(front, split_in_left, buf.get(), is_less) },
+        || unsafe { parallel_merge(back, mid - split_in_left, back_buf.get(), is_less) },
+    );
+}
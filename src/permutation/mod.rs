@@ -0,0 +1,53 @@
+//! Permutation helpers shared by the FFI-backed sort modules ([`crate::cpp_pdqsort`],
+//! [`crate::cpp_std_stable`]): sorting a permutation of indices instead of the data itself, and
+//! later applying that permutation to one or more parallel arrays.
+
+use std::cmp::Ordering;
+use std::mem::MaybeUninit;
+use std::ptr;
+
+/// Sorts a permutation of `0..data.len()` by `compare(&data[i], &data[j])` instead of reordering
+/// `data` itself, so the same permutation can be applied to several parallel arrays via
+/// [`apply_permutation`].
+///
+/// `sort_indices_by` is the caller's own `i32` FFI trampoline: ordering is fully determined by
+/// `compare`, so the indices' bit pattern never needs to be interpreted as a signed integer.
+pub fn sort_to_indices<T, F, S>(data: &[T], mut compare: F, sort_indices_by: S) -> Vec<u32>
+where
+    F: FnMut(&T, &T) -> Ordering,
+    S: FnOnce(&mut [i32], &mut dyn FnMut(&i32, &i32) -> Ordering),
+{
+    assert!(data.len() <= i32::MAX as usize);
+
+    let mut indices: Vec<i32> = (0..data.len() as i32).collect();
+    sort_indices_by(&mut indices, &mut |a, b| {
+        compare(&data[*a as usize], &data[*b as usize])
+    });
+
+    indices.into_iter().map(|i| i as u32).collect()
+}
+
+/// Reorders `data` in place so that `data[i]` becomes the element that used to be at
+/// `data[indices[i]]`, as produced by [`sort_to_indices`].
+///
+/// # Safety
+///
+/// `indices` must be a permutation of `0..data.len()`: every index in that range must appear in
+/// `indices` exactly once. A duplicate index bit-copies one element into two slots (so it is later
+/// dropped twice) while another element is lost, and an out-of-range index reads out of bounds;
+/// both are undefined behavior.
+pub unsafe fn apply_permutation<T>(data: &mut [T], indices: &[u32]) {
+    debug_assert_eq!(data.len(), indices.len());
+
+    let len = data.len();
+    let mut buf: Vec<MaybeUninit<T>> = (0..len).map(|_| MaybeUninit::uninit()).collect();
+
+    // SAFETY: `indices` is a permutation of `0..len`, so every read below is in bounds and every
+    // slot of `buf` is written to exactly once before the final bulk copy reads it back.
+    unsafe {
+        for (dest, &src_idx) in buf.iter_mut().zip(indices.iter()) {
+            ptr::copy_nonoverlapping(data.as_ptr().add(src_idx as usize), dest.as_mut_ptr(), 1);
+        }
+        ptr::copy_nonoverlapping(buf.as_ptr().cast::<T>(), data.as_mut_ptr(), len);
+    }
+}
@@ -0,0 +1,82 @@
+//! A stable least-significant-digit radix sort over a caller-supplied `u64` key.
+//!
+//! Unlike the `CppSort`/`PdqSort` FFI adapters, this is a plain Rust comparison-free sort, useful
+//! as an O(n*k) baseline to benchmark against the comparison-based `sort_stable_*` backends on
+//! integer-keyed data.
+
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+
+const RADIX_BITS: u32 = 8;
+const RADIX_SIZE: usize = 1 << RADIX_BITS;
+const RADIX_MASK: u64 = (RADIX_SIZE as u64) - 1;
+const PASSES: u32 = u64::BITS / RADIX_BITS;
+
+/// Sorts `data` by the `u64` key that `key` extracts from each element, using a stable
+/// least-significant-digit radix sort.
+///
+/// Processes the key in `PASSES` passes of `RADIX_BITS` bits each. Every pass computes a counting
+/// histogram of the current digit, turns it into a prefix sum of bucket start offsets, then
+/// scatters elements into a same-sized scratch buffer in ascending bucket order. Because elements
+/// within a bucket are visited in their current order, and passes go from least to most
+/// significant digit, the overall sort is stable. Elements are moved between buffers, never cloned.
+pub fn sort_by_key<T, K: FnMut(&T) -> u64>(data: &mut [T], mut key: K) {
+    let len = data.len();
+    if len < 2 {
+        return;
+    }
+
+    let mut keys: Vec<u64> = data.iter().map(|x| key(x)).collect();
+    let mut keys_scratch: Vec<u64> = vec![0; len];
+
+    // `scratch` never logically owns a `T`; it only ever holds shallow, bitwise copies of elements
+    // that are also still "owned" by their other, current location. Using `MaybeUninit` means its
+    // `Vec` drop is a no-op, so none of those shallow copies are ever double-dropped.
+    let mut scratch: Vec<MaybeUninit<T>> = (0..len).map(|_| MaybeUninit::uninit()).collect();
+
+    let mut src_ptr: *mut T = data.as_mut_ptr();
+    let mut dst_ptr: *mut T = scratch.as_mut_ptr().cast::<T>();
+
+    for pass in 0..PASSES {
+        let shift = pass * RADIX_BITS;
+        let digit_of = |k: u64| ((k >> shift) & RADIX_MASK) as usize;
+
+        let mut offsets = [0usize; RADIX_SIZE];
+        for &k in &keys {
+            offsets[digit_of(k)] += 1;
+        }
+
+        let mut running = 0usize;
+        for count in &mut offsets {
+            let bucket_len = *count;
+            *count = running;
+            running += bucket_len;
+        }
+
+        for i in 0..len {
+            let dest = offsets[digit_of(keys[i])];
+            offsets[digit_of(keys[i])] += 1;
+
+            // SAFETY: `src_ptr` and `dst_ptr` point to two distinct, non-overlapping `len`-long
+            // buffers, and `i` and `dest` are both in `0..len`.
+            unsafe {
+                ptr::copy_nonoverlapping(src_ptr.add(i), dst_ptr.add(dest), 1);
+            }
+            keys_scratch[dest] = keys[i];
+        }
+
+        mem::swap(&mut src_ptr, &mut dst_ptr);
+        mem::swap(&mut keys, &mut keys_scratch);
+    }
+
+    // After each pass `src_ptr`/`dst_ptr` are swapped, so after an even number of passes the
+    // result is back in `src_ptr` pointing at `data`; after an odd number it's in the scratch
+    // buffer and needs one final copy back.
+    if PASSES % 2 != 0 {
+        // SAFETY: `src_ptr` points at the scratch buffer here, which holds exactly `len`
+        // initialized elements; `data` is `len` long and distinct from the scratch allocation.
+        unsafe {
+            ptr::copy_nonoverlapping(src_ptr, data.as_mut_ptr(), len);
+        }
+    }
+}